@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// The error type produced when an integer-backed `Delta` fails to
+/// compute an offset between two addresses
+///
+/// This can happen if the distance between the two addresses cannot
+/// be represented by the `Delta` implementor, either because the
+/// underlying `isize` subtraction overflows, because the result
+/// doesn't fit into the (possibly smaller) integer type backing the
+/// relative pointer, or, for the forward-only unsigned integer types,
+/// because the pointee lies before the relative pointer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerDeltaError(pub(crate) IntegerDeltaErrorImpl);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IntegerDeltaErrorImpl {
+    Sub(usize, usize),
+    Conversion(isize),
+    // only ever constructed by the `NonZero*` `Delta` impls in `nightly.rs`
+    #[cfg(feature = "nightly")]
+    InvalidNonZero,
+}
+
+impl fmt::Display for IntegerDeltaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            IntegerDeltaErrorImpl::Sub(a, b) => write!(
+                f,
+                "could not compute the offset between {:#x} and {:#x}",
+                a, b
+            ),
+            IntegerDeltaErrorImpl::Conversion(delta) => write!(
+                f,
+                "the offset {} does not fit into the given `Delta`",
+                delta
+            ),
+            #[cfg(feature = "nightly")]
+            IntegerDeltaErrorImpl::InvalidNonZero => {
+                write!(f, "a non-zero `Delta` cannot have an offset of 0")
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for IntegerDeltaError {}
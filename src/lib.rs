@@ -1,5 +1,5 @@
 #![cfg_attr(feature = "no_std", no_std)]
-#![cfg_attr(feature = "nightly", feature(const_fn, raw))]
+#![cfg_attr(feature = "nightly", feature(ptr_metadata))]
 #![forbid(missing_docs)]
 
 /*!
@@ -25,7 +25,11 @@
 
     ### nightly
 
-    with nightly you get the ability to use trait objects with relative pointers
+    On stable, `RelPtr` only supports `Sized` pointees. With `nightly` enabled,
+    pointer metadata handling is rebuilt on top of the unstable `ptr_metadata`
+    feature (`core::ptr::Pointee`), which additionally unlocks `RelPtr` for
+    slices, `str`, and trait objects, since all of them are just different
+    kinds of pointer metadata
 
     ## Example
 
@@ -45,8 +49,7 @@
     about this
     1) it only took 1 byte to point to another value,
     2) a relative pointer cannot access all memory, only memory near it
-    3) if both the relative pointer and the pointee move together,
-    then the relative pointer will not be invalidated
+    3) if both the relative pointer and the pointee move together, then the relative pointer will not be invalidated
 
     The third point is what makes moveable self-referential structs possible
 
@@ -55,11 +58,17 @@
     which is defaulted to `isize`, because that will cover all of your cases for using
     relative pointers. But if you want to optimize the size of the pointer, you can use
     any type that implements `Delta`. Some types from std that do so are:
-    `i8`, `i16`, `i32`, `i64`, `i128`, and `isize`. Note that the trade off is that as you
-    decrease the size of the offset, you decrease the range to which you can point to.
-    `isize` will cover at least half of addressable memory, so it should work unless you do
-    something really crazy. For self-referential structs use a type whose max value is atleast
-    as big as your struct. i.e. `std::mem::size_of::<T>() <= I::max_value()`.
+    `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `u8`, `u16`, `u32`, `u64`, `u128`, and `usize`.
+    Note that the trade off is that as you decrease the size of the offset, you decrease the
+    range to which you can point to. `isize` will cover at least half of addressable memory,
+    so it should work unless you do something really crazy. For self-referential structs use
+    a type whose max value is atleast as big as your struct. i.e.
+    `std::mem::size_of::<T>() <= I::max_value()`.
+
+    The unsigned integer types (`u8`, `u16`, ...) are forward-only: the pointee must be laid
+    out *after* the `RelPtr` in memory, which is the common case for self-referential structs.
+    In exchange, they get twice the reach of their signed counterpart of the same width, since
+    none of the range is spent on pointing backwards.
 
     Note on usized types: these are harder to get working
 
@@ -131,11 +140,11 @@ mod nightly;
 mod traits;
 mod error;
 
-#[cfg(feature = "nightly")]
-pub use self::nightly::*;
 pub use self::traits::*;
 pub use self::error::*;
 
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
@@ -151,8 +160,8 @@ macro_rules! impl_delta_zeroable {
                 };
 
                 if std::mem::size_of::<Self>() < std::mem::size_of::<isize>() && (
-                    (Self::min_value() as isize) > del ||
-                    (Self::max_value() as isize) < del
+                    (Self::MIN as isize) > del ||
+                    (Self::MAX as isize) < del
                 )
                 {
                     Err(IntegerDeltaError(IntegerDeltaErrorImpl::Conversion(del)))
@@ -180,6 +189,47 @@ macro_rules! impl_delta_zeroable {
 
 impl_delta_zeroable! { i8, i16, i32, i64, i128, isize }
 
+macro_rules! impl_delta_unsigned {
+    ($($type:ty),* $(,)?) => {$(
+        // forward-only: `T` can only be reached by walking *ahead* of the
+        // `RelPtr`, which doubles the addressable range for a given width,
+        // since none of it is wasted on backwards offsets
+        unsafe impl Delta for $type {
+            type Error = IntegerDeltaError;
+
+            fn sub(a: *const u8, b: *const u8) -> Result<Self, Self::Error> {
+                if (a as usize) < (b as usize) {
+                    return Err(IntegerDeltaError(IntegerDeltaErrorImpl::Sub(a as usize, b as usize)));
+                }
+
+                let del = (a as usize) - (b as usize);
+
+                if std::mem::size_of::<Self>() < std::mem::size_of::<usize>()
+                    && del > Self::MAX as usize
+                {
+                    Err(IntegerDeltaError(IntegerDeltaErrorImpl::Conversion(del as isize)))
+                } else {
+                    Ok(del as _)
+                }
+            }
+
+            unsafe fn sub_unchecked(a: *const u8, b: *const u8) -> Self {
+                (a as usize - b as usize) as _
+            }
+
+            unsafe fn add(self, a: *const u8) -> *mut u8 {
+                <*const u8>::offset(a, self as isize) as *mut u8
+            }
+        }
+
+        impl Nullable for $type {
+            const NULL: Self = 0;
+        }
+    )*};
+}
+
+impl_delta_unsigned! { u8, u16, u32, u64, u128, usize }
+
 /**
  * This represents a relative pointers
  *
@@ -208,14 +258,61 @@ impl<T: ?Sized + MetaData, I: Delta> Clone for RelPtr<T, I> {
     }
 }
 
-impl<T: ?Sized + MetaData, I: Delta> Eq for RelPtr<T, I> {}
-impl<T: ?Sized + MetaData, I: Delta> PartialEq for RelPtr<T, I> {
+// `Eq`/`PartialEq`/`PartialOrd`/`Ord`/`Hash` all compare the *stored*
+// offset and metadata, not the resolved pointee: they answer "were these
+// two `RelPtr`s constructed with the same offset and metadata?", not
+// "do these two `RelPtr`s point at the same place?" or "do they point at
+// equal values?". Since an offset is relative to each `RelPtr`'s own
+// storage address, two equal-offset `RelPtr`s living at different
+// addresses compare equal here even though they resolve to different
+// addresses
+impl<T: ?Sized + MetaData, I: Delta> Eq for RelPtr<T, I> where T::Data: Eq {}
+impl<T: ?Sized + MetaData, I: Delta> PartialEq for RelPtr<T, I>
+where
+    T::Data: PartialEq,
+{
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        (self.0, self.1) == (other.0, other.1)
     }
 }
 
-impl<T: ?Sized + MetaData, I: Delta> From<I> for RelPtr<T, I> {
+// SAFETY: a `RelPtr` only ever stores an `I` and a `T::Data`, neither of
+// which borrow from anywhere, so it is `Send`/`Sync` whenever those are
+unsafe impl<T: ?Sized + MetaData, I: Delta + Send> Send for RelPtr<T, I> where T::Data: Send {}
+unsafe impl<T: ?Sized + MetaData, I: Delta + Sync> Sync for RelPtr<T, I> where T::Data: Sync {}
+
+impl<T: ?Sized + MetaData, I: Delta + PartialOrd> PartialOrd for RelPtr<T, I>
+where
+    T::Data: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.0, self.1).partial_cmp(&(other.0, other.1))
+    }
+}
+
+impl<T: ?Sized + MetaData, I: Delta + Ord> Ord for RelPtr<T, I>
+where
+    T::Data: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0, self.1).cmp(&(other.0, other.1))
+    }
+}
+
+impl<T: ?Sized + MetaData, I: Delta + Hash> Hash for RelPtr<T, I>
+where
+    T::Data: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+}
+
+impl<T: ?Sized + MetaData, I: Delta> From<I> for RelPtr<T, I>
+where
+    T::Data: Default,
+{
     fn from(i: I) -> Self {
         Self(i, <T as MetaData>::Data::default(), PhantomData)
     }
@@ -225,23 +322,54 @@ impl<T: ?Sized + MetaData, I: Delta> From<I> for RelPtr<T, I> {
 
 impl<T: ?Sized + MetaData, I: Nullable> RelPtr<T, I> {
     /**
-     * A null relative pointer has an offset of 0, (points to itself)
+     * Check if relative pointer is null
      */
     #[inline(always)]
-    pub fn null() -> Self {
-        Self(I::NULL, <T as MetaData>::Data::default(), PhantomData)
+    pub fn is_null(&self) -> bool {
+        self.0 == I::NULL
     }
+}
 
+impl<T: ?Sized + MetaData, I: Nullable> RelPtr<T, I>
+where
+    T::Data: Default,
+{
     /**
-     * Check if relative pointer is null
+     * A null relative pointer has an offset of 0, (points to itself)
+     *
+     * Note: this is only available when `T`'s pointer metadata has a
+     * sensible default, e.g. it is unavailable for trait objects,
+     * since there is no such thing as a null vtable
      */
     #[inline(always)]
-    pub fn is_null(&self) -> bool {
-        self.0 == I::NULL
+    pub fn null() -> Self {
+        Self(I::NULL, <T as MetaData>::Data::default(), PhantomData)
     }
 }
 
 impl<T: ?Sized + MetaData, I: Delta> RelPtr<T, I> {
+    /**
+     * Builds a relative pointer directly from an offset and a piece of
+     * pointer metadata
+     *
+     * Unlike `RelPtr::from`/`RelPtr::null`, this does not require
+     * `T::Data: Default`, so it is the only way to construct a
+     * `RelPtr<dyn Trait, I>` from outside the crate, since `DynMetadata`
+     * has no sensible default value
+     *
+     * # Safety
+     *
+     * `offset` must be a valid offset for `meta`'s pointee, i.e.
+     * `T::compose(offset.add(self_address), meta)` must be a pointer
+     * that is sound to dereference for as long as the relative pointer
+     * is used, where `self_address` is the address this `RelPtr` is
+     * stored at
+     */
+    #[inline]
+    pub unsafe fn from_raw_parts(offset: I, meta: T::Data) -> Self {
+        Self(offset, meta, PhantomData)
+    }
+
     /**
      * set the offset of a relative pointer,
      * if the offset cannot be calculated using the given
@@ -325,9 +453,66 @@ impl<T: ?Sized + MetaData, I: Delta> RelPtr<T, I> {
     pub unsafe fn as_mut_unchecked(&mut self) -> &mut T {
         &mut *self.as_raw_unchecked()
     }
+
+    /**
+     * Swaps the values pointed to by `self` and `other`, without
+     * deinitializing either one
+     *
+     * the offsets of `self` and `other` are untouched, so both relative
+     * pointers remain valid, and now point to each other's old contents
+     *
+     * # Safety
+     *
+     * Same as `RelPtr::as_raw_unchecked`, for both `self` and `other`
+     *
+     * # Panics
+     *
+     * panics if `self` and `other` don't share the same metadata, e.g.
+     * two `RelPtr<[T]>` of different lengths, since swapping them would
+     * corrupt the shorter fat pointer
+     *
+     * panics if the two pointees partially overlap, since swapping
+     * byte-by-byte would clobber bytes shared between them before they
+     * get a chance to move to the other side. Identical pointees (full
+     * overlap) are fine, and are a no-op
+     */
+    #[inline]
+    pub unsafe fn swap_targets(&self, other: &Self)
+    where
+        T::Data: PartialEq,
+    {
+        assert!(
+            self.1 == other.1,
+            "swap_targets: relative pointers have mismatched metadata"
+        );
+
+        let a = self.as_raw_unchecked();
+        let b = other.as_raw_unchecked();
+
+        if a as *const () == b as *const () {
+            return;
+        }
+
+        let len = std::mem::size_of_val(&*a);
+        let a = a as *mut u8;
+        let b = b as *mut u8;
+
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        assert!(
+            hi as usize >= lo as usize + len,
+            "swap_targets: partially overlapping pointees cannot be swapped"
+        );
+
+        for i in 0..len {
+            std::ptr::swap(a.add(i), b.add(i));
+        }
+    }
 }
 
-impl<T: ?Sized + MetaData, I: Nullable> RelPtr<T, I> {
+impl<T: ?Sized + MetaData, I: Nullable> RelPtr<T, I>
+where
+    T::Data: Default,
+{
     /**
      * Converts the relative pointer into a normal raw pointer
      *
@@ -335,14 +520,14 @@ impl<T: ?Sized + MetaData, I: Nullable> RelPtr<T, I> {
      *
      * # Safety
      *
-     * You must ensure that if the relative pointer was successfully set then 
+     * You must ensure that if the relative pointer was successfully set then
      * the value pointed to does not change it's offset relative to `RelPtr`
      *
      * if the relative pointer was not successfully set `RelPtr::as_raw` returns null,
-     * this function is safe for all types except for trait objects
-     * because the only way to construct a `RelPtr` is to make a null ptr and change it
-     * through `RelPtr::set`, but with trait objects it is impossible to create a v-table
-     * so it will have an invalid v-table (which is UB)
+     * this function is safe for all types whose metadata has a `Default`
+     * (trait objects don't implement `Default` for their metadata, since
+     * there is no such thing as a null v-table, so this method, along with
+     * `RelPtr::null`, is simply unavailable for them)
      */
     #[inline]
     pub unsafe fn as_raw(&self) -> *mut T {
@@ -393,3 +578,108 @@ impl<T: ?Sized + MetaData, I: Nullable> RelPtr<T, I> {
         <*mut T>::as_mut(self.as_raw())
     }
 }
+
+// Slice and `str` ergonomics
+//
+// `[T]` and `str` are unsized, so they only implement `MetaData` under the
+// `nightly` feature (see `traits.rs`); their `Data` (a `usize` length) comes
+// from the same blanket impl as everything else, so a `RelPtr<[T], I>` or
+// `RelPtr<str, I>` already works through the methods above. These are
+// just slice/`str`-flavored names for `as_ref_unchecked`/`as_mut_unchecked`
+// and `as_ref`
+//
+// IMPORTANT: as with every other `RelPtr`, the pointee must move *together*
+// with the `RelPtr` that targets it, e.g. both are fields of the same
+// struct laid out inline. A `RelPtr<[T], I>`/`RelPtr<str, I>` into the
+// backing storage of a `Vec`/`String` does NOT satisfy this: the `Vec`'s
+// heap allocation does not move when the struct holding its header moves,
+// so the offset goes stale and reading through it is UB. Only use these
+// methods to point into storage that is itself inline with the `RelPtr`
+// (e.g. a fixed-size array field), never into a `Vec`/`String`'s buffer
+
+#[cfg(feature = "nightly")]
+impl<T, I: Delta> RelPtr<[T], I> {
+    /**
+     * Gets a slice from the relative pointer
+     *
+     * # Safety
+     *
+     * Same as `RelPtr::as_ref_unchecked`. Additionally, the pointee must
+     * be inline storage that moves together with this `RelPtr` (e.g. an
+     * array field of the same struct) -- a slice into a `Vec`'s backing
+     * allocation does not qualify, since that allocation doesn't move
+     * when the struct holding the `Vec` moves
+     */
+    #[inline]
+    pub unsafe fn as_slice_unchecked(&self) -> &[T] {
+        self.as_ref_unchecked()
+    }
+
+    /**
+     * Gets a mutable slice from the relative pointer
+     *
+     * # Safety
+     *
+     * Same as `RelPtr::as_raw_unchecked`. See `as_slice_unchecked` for the
+     * additional constraint on what the pointee may be
+     */
+    #[inline]
+    pub unsafe fn as_slice_mut_unchecked(&mut self) -> &mut [T] {
+        self.as_mut_unchecked()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, I: Nullable> RelPtr<[T], I> {
+    /**
+     * Gets a slice from the relative pointer,
+     * if the relative pointer is null, then `None` is
+     * returned
+     *
+     * # Safety
+     *
+     * Same as `RelPtr::as_raw`. See `as_slice_unchecked` for the
+     * additional constraint on what the pointee may be
+     */
+    #[inline]
+    pub unsafe fn as_slice(&self) -> Option<&[T]> {
+        self.as_ref()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I: Delta> RelPtr<str, I> {
+    /**
+     * Gets a `str` from the relative pointer
+     *
+     * # Safety
+     *
+     * Same as `RelPtr::as_ref_unchecked`. Additionally, the pointee must
+     * be inline storage that moves together with this `RelPtr` (e.g. a
+     * fixed-size byte buffer field of the same struct) -- a slice into a
+     * `String`'s backing allocation does not qualify, since that
+     * allocation doesn't move when the struct holding the `String` moves
+     */
+    #[inline]
+    pub unsafe fn as_str_unchecked(&self) -> &str {
+        self.as_ref_unchecked()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I: Nullable> RelPtr<str, I> {
+    /**
+     * Gets a `str` from the relative pointer,
+     * if the relative pointer is null, then `None` is
+     * returned
+     *
+     * # Safety
+     *
+     * Same as `RelPtr::as_raw`. See `as_str_unchecked` for the
+     * additional constraint on what the pointee may be
+     */
+    #[inline]
+    pub unsafe fn as_str(&self) -> Option<&str> {
+        self.as_ref()
+    }
+}
@@ -0,0 +1,264 @@
+use super::RelPtr;
+
+#[test]
+fn null_is_null() {
+    let ptr: RelPtr<u32, isize> = RelPtr::null();
+
+    assert!(ptr.is_null());
+}
+
+#[test]
+fn set_and_read_back() {
+    struct Owner {
+        value: u32,
+        ptr: RelPtr<u32, isize>,
+    }
+
+    let mut owner = Owner {
+        value: 10,
+        ptr: RelPtr::null(),
+    };
+
+    owner.ptr.set(&owner.value).unwrap();
+
+    assert_eq!(unsafe { *owner.ptr.as_ref_unchecked() }, 10);
+}
+
+#[test]
+fn eq_compares_contents_not_identity() {
+    // two independently constructed `RelPtr`s with the same offset and
+    // metadata must be `==`, not just `Ord`-equal or hash-equal; a prior
+    // version compared `self`/`other`'s own addresses instead, which broke
+    // the basic `Eq`/`Ord`/`Hash` contract
+    let a: RelPtr<u32, isize> = RelPtr::from(5);
+    let b: RelPtr<u32, isize> = RelPtr::from(5);
+
+    assert!(a == b);
+    assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+
+    let c: RelPtr<u32, isize> = RelPtr::from(6);
+    assert!(a != c);
+}
+
+#[test]
+fn swap_targets_swaps_two_values() {
+    struct Owner {
+        a: u32,
+        b: u32,
+        ptr_a: RelPtr<u32, isize>,
+        ptr_b: RelPtr<u32, isize>,
+    }
+
+    let mut owner = Owner {
+        a: 1,
+        b: 2,
+        ptr_a: RelPtr::null(),
+        ptr_b: RelPtr::null(),
+    };
+
+    owner.ptr_a.set(&owner.a).unwrap();
+    owner.ptr_b.set(&owner.b).unwrap();
+
+    unsafe { owner.ptr_a.swap_targets(&owner.ptr_b) };
+
+    assert_eq!(owner.a, 2);
+    assert_eq!(owner.b, 1);
+}
+
+#[test]
+fn swap_targets_same_address_is_noop() {
+    struct Owner {
+        value: u32,
+        ptr: RelPtr<u32, isize>,
+    }
+
+    let mut owner = Owner {
+        value: 42,
+        ptr: RelPtr::null(),
+    };
+
+    owner.ptr.set(&owner.value).unwrap();
+
+    // swapping a `RelPtr` with itself resolves both sides to the same
+    // address, which must be a no-op; note that a *copy* of `owner.ptr`
+    // would not do, since its offset is relative to its own storage
+    // location, not to the original
+    unsafe { owner.ptr.swap_targets(&owner.ptr) };
+
+    assert_eq!(owner.value, 42);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+#[should_panic(expected = "mismatched metadata")]
+fn swap_targets_panics_on_metadata_mismatch() {
+    struct Owner {
+        data: [u32; 4],
+        ptr_a: RelPtr<[u32], isize>,
+        ptr_b: RelPtr<[u32], isize>,
+    }
+
+    let mut owner = Owner {
+        data: [1, 2, 3, 4],
+        ptr_a: RelPtr::null(),
+        ptr_b: RelPtr::null(),
+    };
+
+    owner.ptr_a.set(&owner.data[0..1]).unwrap();
+    owner.ptr_b.set(&owner.data[1..3]).unwrap();
+
+    unsafe { owner.ptr_a.swap_targets(&owner.ptr_b) };
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+#[should_panic(expected = "mismatched metadata")]
+fn swap_targets_panics_on_same_start_address_mismatch() {
+    // two differently-sized slices that happen to start at the same
+    // address: comparing the raw data pointers alone (ignoring metadata)
+    // would wrongly treat this as the "identical pointee" no-op case, so
+    // the metadata check must run before the address-equality short-circuit
+    struct Owner {
+        data: [u32; 4],
+        ptr_a: RelPtr<[u32], isize>,
+        ptr_b: RelPtr<[u32], isize>,
+    }
+
+    let mut owner = Owner {
+        data: [1, 2, 3, 4],
+        ptr_a: RelPtr::null(),
+        ptr_b: RelPtr::null(),
+    };
+
+    owner.ptr_a.set(&owner.data[0..1]).unwrap();
+    owner.ptr_b.set(&owner.data[0..3]).unwrap();
+
+    unsafe { owner.ptr_a.swap_targets(&owner.ptr_b) };
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+#[should_panic(expected = "overlapping")]
+fn swap_targets_panics_on_partial_overlap() {
+    struct Owner {
+        data: [u8; 5],
+        ptr_a: RelPtr<[u8], isize>,
+        ptr_b: RelPtr<[u8], isize>,
+    }
+
+    let mut owner = Owner {
+        data: [1, 2, 3, 4, 5],
+        ptr_a: RelPtr::null(),
+        ptr_b: RelPtr::null(),
+    };
+
+    owner.ptr_a.set(&owner.data[0..3]).unwrap();
+    owner.ptr_b.set(&owner.data[1..4]).unwrap();
+
+    unsafe { owner.ptr_a.swap_targets(&owner.ptr_b) };
+}
+
+#[test]
+fn unsigned_delta_is_forward_only() {
+    // `repr(C)` pins the field order so `before` and `target` are
+    // guaranteed to land on either side of `ptr`, which is what this
+    // test is actually exercising
+    #[repr(C)]
+    struct Owner {
+        before: u32,
+        ptr: RelPtr<u32, u8>,
+        target: u32,
+    }
+
+    let mut owner = Owner {
+        before: 0,
+        target: 1,
+        ptr: RelPtr::null(),
+    };
+
+    // `target` is laid out after `ptr`, so this must succeed
+    owner.ptr.set(&owner.target).unwrap();
+    assert_eq!(unsafe { *owner.ptr.as_ref_unchecked() }, 1);
+
+    // `before` is laid out before `ptr`, so an unsigned `Delta` must
+    // reject it rather than silently wrapping
+    assert!(owner.ptr.set(&owner.before).is_err());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn slice_survives_move_when_inline() {
+    // correct usage: the pointee is an array field laid out inline in the
+    // same struct as the `RelPtr`, so it moves together with it. Pointing
+    // into a `Vec`/`String`'s heap-backed storage instead is NOT valid,
+    // since that allocation does not move with the struct -- see the
+    // `# Safety` docs on `RelPtr::as_slice_unchecked`
+    struct Owner {
+        data: [u32; 4],
+        ptr: RelPtr<[u32], isize>,
+    }
+
+    let mut owner = Owner {
+        data: [10, 20, 30, 40],
+        ptr: RelPtr::null(),
+    };
+
+    owner.ptr.set(&owner.data[1..3]).unwrap();
+
+    assert_eq!(unsafe { owner.ptr.as_slice_unchecked() }, &[20, 30]);
+
+    let owner = Box::new(owner);
+
+    assert_eq!(unsafe { owner.ptr.as_slice_unchecked() }, &[20, 30]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn trait_object_round_trip() {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    trait Speak {
+        fn speak(&self) -> &'static str;
+    }
+
+    struct Dog;
+    impl Speak for Dog {
+        fn speak(&self) -> &'static str {
+            "woof"
+        }
+    }
+
+    struct Owner {
+        dog: Dog,
+        ptr: RelPtr<dyn Speak, isize>,
+    }
+
+    let dog = Dog;
+    let meta = core::ptr::metadata(&dog as &dyn Speak);
+
+    // `RelPtr::null`/`RelPtr::from` are unavailable here since `DynMetadata`
+    // has no `Default` impl -- `from_raw_parts` is the only way in
+    let mut owner = Owner {
+        dog,
+        ptr: unsafe { RelPtr::from_raw_parts(0, meta) },
+    };
+
+    owner.ptr.set(&owner.dog).unwrap();
+
+    assert_eq!(unsafe { owner.ptr.as_ref_unchecked() }.speak(), "woof");
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<RelPtr<dyn Speak, isize>>();
+
+    // a `RelPtr` with the same offset and metadata must compare/hash equal
+    let other = owner.ptr;
+    assert_eq!(owner.ptr.cmp(&other), Ordering::Equal);
+
+    let mut h1 = DefaultHasher::new();
+    let mut h2 = DefaultHasher::new();
+    owner.ptr.hash(&mut h1);
+    other.hash(&mut h2);
+    assert_eq!(h1.finish(), h2.finish());
+}
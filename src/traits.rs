@@ -0,0 +1,110 @@
+#[cfg(feature = "nightly")]
+use core::ptr::Pointee;
+
+/// `Delta` represents a type that can be used to store the offset
+/// of a `RelPtr`
+///
+/// # Safety
+///
+/// `sub`, `sub_unchecked` and `add` must agree with each other, i.e.
+/// `Self::add(Self::sub(a, b)?, b) == a` for any `a` and `b` for which
+/// `sub` succeeds
+pub unsafe trait Delta: Copy + Eq {
+    /// The error returned when the offset between two addresses
+    /// cannot be represented as `Self`
+    type Error;
+
+    /// Computes the offset between `a` and `b`, i.e. `a - b`
+    ///
+    /// if the offset cannot be represented by `Self`, then an error
+    /// is returned instead
+    fn sub(a: *const u8, b: *const u8) -> Result<Self, Self::Error>;
+
+    /// Computes the offset between `a` and `b`, i.e. `a - b`
+    ///
+    /// # Safety
+    ///
+    /// the offset between `a` and `b` must be representable as `Self`
+    unsafe fn sub_unchecked(a: *const u8, b: *const u8) -> Self;
+
+    /// Applies the offset `self` to `a`
+    ///
+    /// # Safety
+    ///
+    /// `a` plus the offset `self` must not overflow the address space
+    unsafe fn add(self, a: *const u8) -> *mut u8;
+}
+
+/// `Nullable` represents a `Delta` that has a sentinel value that
+/// can be used to represent a null `RelPtr`
+pub trait Nullable: Delta {
+    /// the sentinel value used to represent a null offset
+    const NULL: Self;
+}
+
+/// `MetaData` abstracts over the pointer metadata that has to be
+/// stored alongside a `RelPtr`'s offset in order to reconstruct a
+/// `*mut T`
+///
+/// On stable, this is implemented only for `Sized` types, where the
+/// metadata is trivially `()`. With the `nightly` feature enabled, it
+/// is instead implemented generically for every `T` on top of
+/// `core::ptr::Pointee`, so slices, `str`, and trait objects get a
+/// `Data` too (the element length, or a `DynMetadata` vtable handle,
+/// respectively) and there is no need to implement it by hand
+///
+/// # Safety
+///
+/// `decompose` and `compose` must round-trip, i.e.
+/// `Self::compose(Self::decompose(value).0 as _, Self::decompose(value).1)`
+/// must produce a pointer that is valid to dereference for as long as
+/// `value` is
+pub unsafe trait MetaData {
+    /// the metadata required to go from a thin data pointer back to
+    /// a pointer to `Self`
+    type Data: Copy;
+
+    /// splits a reference to `Self` into its data pointer and metadata
+    fn decompose(value: &Self) -> (*const u8, Self::Data);
+
+    /// reassembles a pointer to `Self` from a data pointer and metadata
+    ///
+    /// # Safety
+    ///
+    /// `ptr` and `meta` must have come from a matching call to `decompose`
+    /// (or otherwise be a valid data pointer/metadata pair for `Self`)
+    unsafe fn compose(ptr: *mut u8, meta: Self::Data) -> *mut Self;
+}
+
+#[cfg(not(feature = "nightly"))]
+unsafe impl<T> MetaData for T {
+    type Data = ();
+
+    #[inline]
+    fn decompose(value: &Self) -> (*const u8, ()) {
+        (value as *const T as *const u8, ())
+    }
+
+    #[inline]
+    unsafe fn compose(ptr: *mut u8, _meta: ()) -> *mut Self {
+        ptr as *mut T
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<T: ?Sized> MetaData for T
+where
+    T: Pointee,
+{
+    type Data = <T as Pointee>::Metadata;
+
+    #[inline]
+    fn decompose(value: &Self) -> (*const u8, Self::Data) {
+        (value as *const T as *const u8, core::ptr::metadata(value))
+    }
+
+    #[inline]
+    unsafe fn compose(ptr: *mut u8, meta: Self::Data) -> *mut Self {
+        core::ptr::from_raw_parts_mut(ptr as *mut (), meta)
+    }
+}